@@ -0,0 +1,253 @@
+use std::io;
+
+use crate::wav::{Endianness, SampleFormat, WaveHeader};
+
+/// Block size (in bytes) used to refill the internal read buffer. Chosen to be
+/// a few KiB so we never load the whole `data` chunk into memory, while still
+/// amortizing the cost of individual `read` calls.
+const BLOCK_SIZE: usize = 8192;
+
+/// A single decoded sample, normalized to either a signed integer or a float
+/// depending on the stream's `SampleFormat`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    Int(i32),
+    Float(f32),
+}
+
+/// One deinterleaved audio frame: one sample per channel, in channel order.
+pub type Frame = Vec<Sample>;
+
+/// Iterates over the "data" chunk of a WAVE stream, yielding one [`Frame`] at
+/// a time. Reads happen in `BLOCK_SIZE` chunks rather than all at once, so
+/// arbitrarily long streams can be decoded without buffering the whole file.
+pub struct FrameDecoder<'a> {
+    stream: &'a mut dyn io::Read,
+    num_channels: usize,
+    bytes_per_sample: usize,
+    sample_format: SampleFormat,
+    endianness: Endianness,
+    remaining: u64,
+    buf: Vec<u8>,
+    buf_pos: usize,
+    buf_len: usize,
+    error: bool,
+}
+
+impl<'a> FrameDecoder<'a> {
+    pub fn new(header: &WaveHeader, stream: &'a mut dyn io::Read) -> Self {
+        let bytes_per_sample = header.bits_per_sample() as usize / 8;
+        FrameDecoder {
+            stream,
+            num_channels: header.num_channels() as usize,
+            bytes_per_sample,
+            sample_format: header.sample_format,
+            endianness: header.endianness(),
+            remaining: header.data_len as u64,
+            buf: Vec::new(),
+            buf_pos: 0,
+            buf_len: 0,
+            error: false,
+        }
+    }
+
+    fn frame_size(&self) -> usize {
+        self.num_channels * self.bytes_per_sample
+    }
+
+    /// Refills the internal buffer, aligned to whole frames, from the
+    /// underlying stream. Returns `Ok(false)` once the `data` chunk (or the
+    /// stream itself) is exhausted.
+    fn fill_buffer(&mut self) -> Result<bool, String> {
+        if self.remaining == 0 {
+            return Ok(false);
+        }
+
+        let frame_size = self.frame_size();
+        let want = (BLOCK_SIZE / frame_size).max(1) * frame_size;
+        let want = want.min(self.remaining as usize);
+
+        if self.buf.len() < want {
+            self.buf.resize(want, 0);
+        }
+
+        let mut read = 0;
+        while read < want {
+            match self.stream.read(&mut self.buf[read..want]) {
+                Err(e) => return Err(e.to_string()),
+                Ok(0) => break,
+                Ok(n) => read += n,
+            }
+        }
+
+        // Only whole frames are usable; a short final read that doesn't fill
+        // a whole frame indicates a truncated stream.
+        let usable_frames = read / frame_size;
+        if usable_frames == 0 {
+            if read > 0 {
+                return Err(
+                    "Stream ended mid-frame: truncated 'data' chunk.".to_string()
+                );
+            }
+            return Ok(false);
+        }
+
+        self.buf_len = usable_frames * frame_size;
+        self.buf_pos = 0;
+        self.remaining -= self.buf_len as u64;
+        Ok(true)
+    }
+
+    fn decode_sample(&self, raw: &[u8]) -> Sample {
+        match (self.sample_format, self.bytes_per_sample) {
+            (SampleFormat::PcmInt, 1) => {
+                // 8-bit PCM is unsigned with a bias of 128; a single byte has
+                // no byte order to speak of.
+                Sample::Int(raw[0] as i32 - 128)
+            }
+            (SampleFormat::PcmInt, 2) => {
+                let bytes = [raw[0], raw[1]];
+                let v = match self.endianness {
+                    Endianness::Little => i16::from_le_bytes(bytes),
+                    Endianness::Big => i16::from_be_bytes(bytes),
+                };
+                Sample::Int(v as i32)
+            }
+            (SampleFormat::PcmInt, 3) => {
+                // Packed 24-bit, sign-extended into i32.
+                let unsigned = match self.endianness {
+                    Endianness::Little => u32::from_le_bytes([raw[0], raw[1], raw[2], 0]),
+                    Endianness::Big => u32::from_be_bytes([0, raw[0], raw[1], raw[2]]),
+                };
+                let shifted = (unsigned << 8) as i32;
+                Sample::Int(shifted >> 8)
+            }
+            (SampleFormat::PcmInt, 4) => {
+                let bytes = [raw[0], raw[1], raw[2], raw[3]];
+                let v = match self.endianness {
+                    Endianness::Little => i32::from_le_bytes(bytes),
+                    Endianness::Big => i32::from_be_bytes(bytes),
+                };
+                Sample::Int(v)
+            }
+            (SampleFormat::IeeeFloat, 4) => {
+                let bytes = [raw[0], raw[1], raw[2], raw[3]];
+                let v = match self.endianness {
+                    Endianness::Little => f32::from_le_bytes(bytes),
+                    Endianness::Big => f32::from_be_bytes(bytes),
+                };
+                Sample::Float(v)
+            }
+            (format, width) => {
+                unreachable!("unsupported sample encoding: {format:?} at {width} bytes wide")
+            }
+        }
+    }
+}
+
+impl Iterator for FrameDecoder<'_> {
+    type Item = Result<Frame, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error {
+            return None;
+        }
+
+        if self.buf_pos >= self.buf_len {
+            match self.fill_buffer() {
+                Err(e) => {
+                    self.error = true;
+                    return Some(Err(e));
+                }
+                Ok(false) => return None,
+                Ok(true) => {}
+            }
+        }
+
+        let frame_size = self.frame_size();
+        let frame_bytes = &self.buf[self.buf_pos..self.buf_pos + frame_size];
+        let frame = (0..self.num_channels)
+            .map(|ch| {
+                let start = ch * self.bytes_per_sample;
+                self.decode_sample(&frame_bytes[start..start + self.bytes_per_sample])
+            })
+            .collect();
+        self.buf_pos += frame_size;
+
+        Some(Ok(frame))
+    }
+}
+
+#[test]
+fn test_decode_16bit_stereo_frames() {
+    let samples: [i16; 4] = [0, -1, 100, -100];
+    let mut raw = Vec::new();
+    for s in samples {
+        raw.extend_from_slice(&s.to_le_bytes());
+    }
+
+    let header = crate::wav::test_support::header_for_test(2, 16, SampleFormat::PcmInt, raw.len() as u32);
+    let mut cursor = io::Cursor::new(raw);
+    let mut decoder = FrameDecoder::new(&header, &mut cursor);
+
+    let frame1 = decoder.next().unwrap().unwrap();
+    assert_eq!(frame1, vec![Sample::Int(0), Sample::Int(-1)]);
+
+    let frame2 = decoder.next().unwrap().unwrap();
+    assert_eq!(frame2, vec![Sample::Int(100), Sample::Int(-100)]);
+
+    assert!(decoder.next().is_none());
+}
+
+#[test]
+fn test_decode_8bit_mono_bias() {
+    let raw = vec![128u8, 0u8, 255u8];
+    let header = crate::wav::test_support::header_for_test(1, 8, SampleFormat::PcmInt, raw.len() as u32);
+    let mut cursor = io::Cursor::new(raw);
+    let decoder = FrameDecoder::new(&header, &mut cursor);
+
+    let frames: Result<Vec<Frame>, String> = decoder.collect();
+    let frames = frames.unwrap();
+    assert_eq!(frames[0], vec![Sample::Int(0)]);
+    assert_eq!(frames[1], vec![Sample::Int(-128)]);
+    assert_eq!(frames[2], vec![Sample::Int(127)]);
+}
+
+#[test]
+fn test_decode_32bit_float_mono() {
+    let raw_samples: [f32; 2] = [0.5, -0.25];
+    let mut raw = Vec::new();
+    for s in raw_samples {
+        raw.extend_from_slice(&s.to_le_bytes());
+    }
+
+    let header = crate::wav::test_support::header_for_test(1, 32, SampleFormat::IeeeFloat, raw.len() as u32);
+    let mut cursor = io::Cursor::new(raw);
+    let decoder = FrameDecoder::new(&header, &mut cursor);
+
+    let frames: Result<Vec<Frame>, String> = decoder.collect();
+    let frames = frames.unwrap();
+    assert_eq!(frames[0], vec![Sample::Float(0.5)]);
+    assert_eq!(frames[1], vec![Sample::Float(-0.25)]);
+}
+
+#[test]
+fn test_decode_reads_in_blocks_across_many_frames() {
+    // More frames than fit in a single BLOCK_SIZE refill, to exercise the
+    // buffered block-read path rather than a single read() covering it all.
+    let frame_count = BLOCK_SIZE * 3;
+    let mut raw = Vec::with_capacity(frame_count * 2);
+    for i in 0..frame_count {
+        raw.extend_from_slice(&((i % 100) as i16).to_le_bytes());
+    }
+
+    let header = crate::wav::test_support::header_for_test(1, 16, SampleFormat::PcmInt, raw.len() as u32);
+    let mut cursor = io::Cursor::new(raw);
+    let decoder = FrameDecoder::new(&header, &mut cursor);
+
+    let frames: Result<Vec<Frame>, String> = decoder.collect();
+    let frames = frames.unwrap();
+    assert_eq!(frames.len(), frame_count);
+    assert_eq!(frames[0], vec![Sample::Int(0)]);
+    assert_eq!(frames[99], vec![Sample::Int(99)]);
+}
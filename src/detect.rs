@@ -0,0 +1,340 @@
+use std::collections::VecDeque;
+
+use crate::decode::{Frame, Sample};
+
+/// Window size (in samples) used to estimate the local median/MAD around
+/// each candidate sample. Must be odd so there is a well-defined center.
+pub const DEFAULT_WINDOW: usize = 21;
+
+/// Threshold multiplier applied to `1.4826 * MAD` (the usual scale factor
+/// that makes MAD a consistent estimator of the standard deviation for
+/// normally-distributed errors). Typical click detectors use 3-4.
+pub const DEFAULT_K: f64 = 3.5;
+
+/// A contiguous run of flagged samples on one channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClickRegion {
+    pub channel: usize,
+    pub start_sample: u64,
+    pub length: usize,
+    pub peak_deviation: f64,
+}
+
+struct OpenRegion {
+    start: u64,
+    length: usize,
+    peak: f64,
+}
+
+/// Streaming click detector: wraps a frame iterator (e.g. [`crate::decode::FrameDecoder`])
+/// and yields [`ClickRegion`]s as they're found.
+///
+/// For each channel, a sliding window of `window` samples is kept. The center
+/// sample's prediction error is its distance from the window's median; the
+/// window's median absolute deviation (MAD) estimates the local noise scale.
+/// A sample is flagged when `|error| > k * 1.4826 * MAD`. Consecutive flagged
+/// samples on the same channel are merged into a single [`ClickRegion`].
+///
+/// Because this operates frame-by-frame against whatever iterator it's given,
+/// it doesn't care where the underlying reader's block boundaries fall - the
+/// sliding window carries state across calls to `next`, so clicks that straddle
+/// a decoder buffer refill are still detected correctly.
+///
+/// The last `window / 2` samples of the stream are never evaluated as a
+/// window center, since they can never be centered in a full window. The
+/// first `window / 2` samples have no such gap: as soon as a channel's
+/// window fills for the first time, those leading samples are evaluated
+/// against that first window's statistics instead of being silently
+/// skipped.
+pub struct ClickDetector<I> {
+    frames: I,
+    num_channels: usize,
+    window: usize,
+    k: f64,
+    channel_windows: Vec<VecDeque<f64>>,
+    channel_primed: Vec<bool>,
+    open_regions: Vec<Option<OpenRegion>>,
+    pushed: u64,
+    pending: VecDeque<ClickRegion>,
+}
+
+impl<I> ClickDetector<I>
+where
+    I: Iterator<Item = Result<Frame, String>>,
+{
+    pub fn new(frames: I, num_channels: usize) -> Self {
+        Self::with_params(frames, num_channels, DEFAULT_WINDOW, DEFAULT_K)
+    }
+
+    pub fn with_params(frames: I, num_channels: usize, window: usize, k: f64) -> Self {
+        assert!(window % 2 == 1, "window must be odd to have a center sample");
+        ClickDetector {
+            frames,
+            num_channels,
+            window,
+            k,
+            channel_windows: (0..num_channels).map(|_| VecDeque::with_capacity(window)).collect(),
+            channel_primed: vec![false; num_channels],
+            open_regions: (0..num_channels).map(|_| None).collect(),
+            pushed: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn sample_value(sample: Sample) -> f64 {
+        match sample {
+            Sample::Int(i) => i as f64,
+            Sample::Float(f) => f as f64,
+        }
+    }
+
+    /// Pushes one channel's new sample into its sliding window and, once the
+    /// window is full, evaluates the centered sample (and, the very first
+    /// time the window fills, the leading samples that precede the center
+    /// too) and updates any open click region for that channel.
+    fn evaluate_channel(&mut self, ch: usize, value: f64) {
+        {
+            let win = &mut self.channel_windows[ch];
+            win.push_back(value);
+            if win.len() > self.window {
+                win.pop_front();
+            }
+            if win.len() < self.window {
+                return;
+            }
+        }
+
+        let win = &self.channel_windows[ch];
+        let mut sorted: Vec<f64> = win.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let median = median_of_sorted(&sorted);
+
+        let mut abs_errors: Vec<f64> = win.iter().map(|v| (v - median).abs()).collect();
+        abs_errors.sort_by(|a, b| a.total_cmp(b));
+        let mad = median_of_sorted(&abs_errors);
+
+        let center_offset = self.window / 2;
+
+        if !self.channel_primed[ch] {
+            // The window has just filled for the first time. Offsets before
+            // `center_offset` can never be centered in a full window of
+            // their own (that would need samples before the start of the
+            // stream), so evaluate them now against this first window's
+            // statistics rather than silently dropping them.
+            self.channel_primed[ch] = true;
+            for offset in 0..center_offset {
+                self.evaluate_offset(ch, offset, median, mad);
+            }
+        }
+
+        self.evaluate_offset(ch, center_offset, median, mad);
+    }
+
+    /// Flags (or un-flags) the sample at `offset` within `ch`'s current
+    /// window, given that window's already-computed `median`/`mad`, and
+    /// updates any open click region for that channel accordingly.
+    fn evaluate_offset(&mut self, ch: usize, offset: usize, median: f64, mad: f64) {
+        let center_value = self.channel_windows[ch][offset];
+        let center_error = center_value - median;
+
+        let scale = 1.4826 * mad;
+        let flagged = if scale > f64::EPSILON {
+            center_error.abs() > self.k * scale
+        } else {
+            // A perfectly flat local neighbourhood: any deviation at all is a click.
+            center_error.abs() > f64::EPSILON
+        };
+
+        let center_index = self.pushed - self.window as u64 + offset as u64;
+
+        match (&mut self.open_regions[ch], flagged) {
+            (Some(region), true) => {
+                region.length += 1;
+                region.peak = region.peak.max(center_error.abs());
+            }
+            (None, true) => {
+                self.open_regions[ch] = Some(OpenRegion {
+                    start: center_index,
+                    length: 1,
+                    peak: center_error.abs(),
+                });
+            }
+            (Some(_), false) => {
+                let region = self.open_regions[ch].take().unwrap();
+                self.pending.push_back(ClickRegion {
+                    channel: ch,
+                    start_sample: region.start,
+                    length: region.length,
+                    peak_deviation: region.peak,
+                });
+            }
+            (None, false) => {}
+        }
+    }
+
+    fn flush_open_regions(&mut self) {
+        for ch in 0..self.num_channels {
+            if let Some(region) = self.open_regions[ch].take() {
+                self.pending.push_back(ClickRegion {
+                    channel: ch,
+                    start_sample: region.start,
+                    length: region.length,
+                    peak_deviation: region.peak,
+                });
+            }
+        }
+    }
+}
+
+impl<I> Iterator for ClickDetector<I>
+where
+    I: Iterator<Item = Result<Frame, String>>,
+{
+    type Item = Result<ClickRegion, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(region) = self.pending.pop_front() {
+                return Some(Ok(region));
+            }
+
+            match self.frames.next() {
+                Some(Err(e)) => return Some(Err(e)),
+                Some(Ok(frame)) => {
+                    self.pushed += 1;
+                    for (ch, sample) in frame.into_iter().enumerate() {
+                        let value = Self::sample_value(sample);
+                        self.evaluate_channel(ch, value);
+                    }
+                }
+                None => {
+                    self.flush_open_regions();
+                    if self.pending.is_empty() {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn median_of_sorted(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Drives a [`ClickDetector`] to completion, printing one line per detected
+/// click region with its timestamp (`start_sample / sample_rate`), channel,
+/// length in samples, and peak deviation.
+pub fn print_report<I>(detector: ClickDetector<I>, sample_rate: u32) -> Result<Vec<ClickRegion>, String>
+where
+    I: Iterator<Item = Result<Frame, String>>,
+{
+    let mut regions = Vec::new();
+    for result in detector {
+        let region = result?;
+        let timestamp = region.start_sample as f64 / sample_rate as f64;
+        println!(
+            "click: channel={} t={:.6}s length={} peak={:.1}",
+            region.channel, timestamp, region.length, region.peak_deviation
+        );
+        regions.push(region);
+    }
+    Ok(regions)
+}
+
+#[test]
+fn test_median_of_sorted() {
+    assert_eq!(median_of_sorted(&[1.0, 2.0, 3.0]), 2.0);
+    assert_eq!(median_of_sorted(&[1.0, 2.0, 3.0, 4.0]), 2.5);
+}
+
+#[test]
+fn test_detects_single_sample_click() {
+    // Quiet signal near zero, with one large spike in the middle.
+    let mut samples = vec![0i32; 41];
+    samples[20] = 10_000;
+    let frames: Vec<Result<Frame, String>> = samples
+        .into_iter()
+        .map(|s| Ok(vec![Sample::Int(s)]))
+        .collect();
+
+    let detector = ClickDetector::new(frames.into_iter(), 1);
+    let regions: Vec<ClickRegion> = detector.map(|r| r.unwrap()).collect();
+
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].channel, 0);
+    assert_eq!(regions[0].start_sample, 20);
+    assert_eq!(regions[0].length, 1);
+}
+
+#[test]
+fn test_does_not_panic_on_nan_sample() {
+    // A NaN sample (e.g. from a malformed float WAV) must not make the
+    // window sort panic.
+    let mut samples = vec![0.0f32; 41];
+    samples[20] = f32::NAN;
+    let frames: Vec<Result<Frame, String>> = samples
+        .into_iter()
+        .map(|s| Ok(vec![Sample::Float(s)]))
+        .collect();
+
+    let detector = ClickDetector::new(frames.into_iter(), 1);
+    let _regions: Vec<ClickRegion> = detector.map(|r| r.unwrap()).collect();
+}
+
+#[test]
+fn test_detects_click_near_start_of_stream() {
+    // A spike at sample index 2 sits well inside the leading `window / 2`
+    // samples (default window 21), which used to never get evaluated.
+    let mut samples = vec![0i32; 41];
+    samples[2] = 10_000;
+    let frames: Vec<Result<Frame, String>> = samples
+        .into_iter()
+        .map(|s| Ok(vec![Sample::Int(s)]))
+        .collect();
+
+    let detector = ClickDetector::new(frames.into_iter(), 1);
+    let regions: Vec<ClickRegion> = detector.map(|r| r.unwrap()).collect();
+
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].channel, 0);
+    assert_eq!(regions[0].start_sample, 2);
+    assert_eq!(regions[0].length, 1);
+}
+
+#[test]
+fn test_no_clicks_in_silence() {
+    let samples = vec![0i32; 41];
+    let frames: Vec<Result<Frame, String>> = samples
+        .into_iter()
+        .map(|s| Ok(vec![Sample::Int(s)]))
+        .collect();
+
+    let detector = ClickDetector::new(frames.into_iter(), 1);
+    let regions: Vec<ClickRegion> = detector.map(|r| r.unwrap()).collect();
+
+    assert!(regions.is_empty());
+}
+
+#[test]
+fn test_groups_consecutive_flagged_samples_into_one_region() {
+    let mut samples = vec![0i32; 41];
+    samples[20] = 10_000;
+    samples[21] = 9_000;
+    let frames: Vec<Result<Frame, String>> = samples
+        .into_iter()
+        .map(|s| Ok(vec![Sample::Int(s)]))
+        .collect();
+
+    let detector = ClickDetector::new(frames.into_iter(), 1);
+    let regions: Vec<ClickRegion> = detector.map(|r| r.unwrap()).collect();
+
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].start_sample, 20);
+    assert_eq!(regions[0].length, 2);
+}
@@ -1,6 +1,14 @@
+mod decode;
+mod detect;
+mod repair;
 mod wav;
+mod write;
 
 fn main() {
+    // `--report`/`--report-only` prints detected click regions instead of
+    // repairing and re-encoding the stream.
+    let report_only = std::env::args().any(|arg| arg == "--report" || arg == "--report-only");
+
     // Read stdin as a raw WAVE stream
     let mut file = std::io::stdin();
 
@@ -13,7 +21,57 @@ fn main() {
         Ok(h) => h,
     };
 
+    // Decode the whole "data" chunk so click regions can be repaired by
+    // interpolating across good samples bracketing the gap on both sides.
+    let mut frames = Vec::new();
+    for frame in decode::FrameDecoder::new(&header, &mut file) {
+        match frame {
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+            Ok(f) => frames.push(f),
+        }
+    }
+
     // Scan audio stream for clicks
+    let num_channels = header.num_channels() as usize;
+    let detector = detect::ClickDetector::new(frames.iter().cloned().map(Ok), num_channels);
+
+    if report_only {
+        if let Err(e) = detect::print_report(detector, header.sample_rate()) {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut regions = Vec::new();
+    for region in detector {
+        match region {
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+            Ok(r) => regions.push(r),
+        }
+    }
+    eprintln!("declick-rs: repairing {} click region(s)", regions.len());
+
+    repair::repair(&mut frames, &regions);
 
-    println!("{}", header);
+    let stdout = std::io::stdout();
+    let mut stdout = stdout.lock();
+    if let Err(e) = write::write_wave(
+        &mut stdout,
+        header.num_channels(),
+        header.sample_rate(),
+        header.bits_per_sample(),
+        header.sample_format,
+        header.endianness(),
+        &frames,
+    ) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
 }
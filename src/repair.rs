@@ -0,0 +1,161 @@
+use crate::decode::{Frame, Sample};
+use crate::detect::ClickRegion;
+
+/// Click regions of at most this many samples are repaired with plain linear
+/// interpolation; a Hermite spline needs enough neighbouring context on both
+/// sides to produce a meaningful tangent, which isn't worth it for a one- or
+/// two-sample blip.
+const LINEAR_FALLBACK_MAX_LENGTH: usize = 2;
+
+/// Replaces each flagged click region in `frames` with interpolated samples,
+/// bracketed by the good samples on either side of the gap.
+///
+/// Uses a cubic Hermite spline through the two samples immediately
+/// bracketing the gap, with tangents estimated from one further sample on
+/// each side (a finite-difference / Catmull-Rom style tangent, adjusted for
+/// the actual sample spacing since the gap can be wider than the outer
+/// context). Falls back to linear interpolation between the bracketing
+/// samples when the gap is short or sits too close to the start/end of the
+/// stream to have that extra context. A region with no good sample on one
+/// side (one that starts at the first frame or runs to the last frame) is
+/// left unchanged.
+pub fn repair(frames: &mut [Frame], regions: &[ClickRegion]) {
+    for region in regions {
+        repair_region(frames, region);
+    }
+}
+
+fn repair_region(frames: &mut [Frame], region: &ClickRegion) {
+    let ch = region.channel;
+    let start = region.start_sample as usize;
+    let length = region.length;
+    let end = start + length; // first good sample after the gap
+
+    if start == 0 || end >= frames.len() {
+        // No good sample on one side of the gap; nothing sound to anchor on.
+        return;
+    }
+
+    let value_at = |idx: usize| sample_to_f64(frames[idx][ch]);
+    let x1 = start - 1;
+    let x2 = end;
+    let v1 = value_at(x1);
+    let v2 = value_at(x2);
+    let h = (x2 - x1) as f64;
+
+    let use_linear = length <= LINEAR_FALLBACK_MAX_LENGTH || x1 < 1 || x2 + 1 >= frames.len();
+
+    let template = frames[x1][ch];
+
+    if use_linear {
+        for (frame_idx, frame) in frames.iter_mut().enumerate().take(end).skip(start) {
+            let u = (frame_idx - x1) as f64 / h;
+            frame[ch] = f64_to_sample_like(template, linear(v1, v2, u));
+        }
+        return;
+    }
+
+    let x0 = x1 - 1;
+    let x3 = x2 + 1;
+    let v0 = value_at(x0);
+    let v3 = value_at(x3);
+
+    // Finite-difference tangents at the two bracketing samples, scaled by
+    // the actual distance to their neighbours rather than assuming the
+    // outer context is spaced the same as the gap.
+    let m1 = (v2 - v0) / (x2 - x0) as f64;
+    let m2 = (v3 - v1) / (x3 - x1) as f64;
+
+    for (frame_idx, frame) in frames.iter_mut().enumerate().take(end).skip(start) {
+        let u = (frame_idx - x1) as f64 / h;
+        let v = hermite(v1, v2, m1, m2, h, u);
+        frame[ch] = f64_to_sample_like(template, v);
+    }
+}
+
+fn sample_to_f64(sample: Sample) -> f64 {
+    match sample {
+        Sample::Int(i) => i as f64,
+        Sample::Float(f) => f as f64,
+    }
+}
+
+fn f64_to_sample_like(template: Sample, v: f64) -> Sample {
+    match template {
+        Sample::Int(_) => Sample::Int(v.round() as i32),
+        Sample::Float(_) => Sample::Float(v as f32),
+    }
+}
+
+fn linear(v1: f64, v2: f64, u: f64) -> f64 {
+    v1 + (v2 - v1) * u
+}
+
+/// Cubic Hermite spline over `[0, h]`, parameterized by `u = x / h`, with
+/// values `v1`/`v2` at the endpoints and tangents `m1`/`m2` (in units per
+/// sample) there.
+fn hermite(v1: f64, v2: f64, m1: f64, m2: f64, h: f64, u: f64) -> f64 {
+    let u2 = u * u;
+    let u3 = u2 * u;
+    let h00 = 2.0 * u3 - 3.0 * u2 + 1.0;
+    let h10 = u3 - 2.0 * u2 + u;
+    let h01 = -2.0 * u3 + 3.0 * u2;
+    let h11 = u3 - u2;
+    h00 * v1 + h10 * h * m1 + h01 * v2 + h11 * h * m2
+}
+
+#[test]
+fn test_linear_fallback_for_short_gap() {
+    let mut frames: Vec<Frame> = (0..10).map(|i| vec![Sample::Int(i * 10)]).collect();
+    let region = ClickRegion {
+        channel: 0,
+        start_sample: 4,
+        length: 1,
+        peak_deviation: 0.0,
+    };
+    repair(&mut frames, &[region]);
+    // Between frame 3 (value 30) and frame 5 (value 50), the midpoint is 40.
+    assert_eq!(frames[4][0], Sample::Int(40));
+}
+
+#[test]
+fn test_hermite_repairs_longer_gap_on_a_linear_ramp() {
+    // A perfectly linear ramp (step 10) with a 3-sample gap; the repaired
+    // samples should fall back onto that same line.
+    let values: Vec<i32> = (0..10).map(|i| i * 10).collect();
+    let mut frames: Vec<Frame> = values.iter().map(|&v| vec![Sample::Int(v)]).collect();
+    let region = ClickRegion {
+        channel: 0,
+        start_sample: 4,
+        length: 3,
+        peak_deviation: 0.0,
+    };
+    // Corrupt the gap so the repair actually has to reconstruct it.
+    frames[4][0] = Sample::Int(9999);
+    frames[5][0] = Sample::Int(9999);
+    frames[6][0] = Sample::Int(9999);
+
+    repair(&mut frames, &[region]);
+
+    let as_i32 = |s: Sample| match s {
+        Sample::Int(i) => i,
+        Sample::Float(_) => panic!("expected Int sample"),
+    };
+    assert_eq!(as_i32(frames[4][0]), 40);
+    assert_eq!(as_i32(frames[5][0]), 50);
+    assert_eq!(as_i32(frames[6][0]), 60);
+}
+
+#[test]
+fn test_does_not_repair_region_touching_stream_edge() {
+    let mut frames: Vec<Frame> = (0..5).map(|i| vec![Sample::Int(i * 10)]).collect();
+    let region = ClickRegion {
+        channel: 0,
+        start_sample: 0,
+        length: 1,
+        peak_deviation: 0.0,
+    };
+    let before = frames.clone();
+    repair(&mut frames, &[region]);
+    assert_eq!(frames, before);
+}
@@ -0,0 +1,216 @@
+use std::io;
+
+use crate::decode::{Frame, Sample};
+use crate::wav::{Endianness, SampleFormat};
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// Writes a canonical 44-byte-header WAVE file (RIFF/WAVE/`fmt `/`data`) to
+/// `out`, re-encoding `frames` to `bits_per_sample`/`sample_format`, in the
+/// given `endianness` ("RIFF" container for `Little`, "RIFX" for `Big`).
+///
+/// `chunk_size`, `byte_rate`, `block_align` and the `data` chunk's size are
+/// all computed here rather than taken from the original stream, since a
+/// repair pass can in principle change the sample count.
+pub fn write_wave(
+    out: &mut dyn io::Write,
+    num_channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    sample_format: SampleFormat,
+    endianness: Endianness,
+    frames: &[Frame],
+) -> Result<(), String> {
+    let bytes_per_sample = bits_per_sample as u32 / 8;
+    let block_align = num_channels as u32 * bytes_per_sample;
+    let byte_rate = sample_rate * block_align;
+    let data_size = frames.len() as u32 * block_align;
+    let chunk_size = 36 + data_size;
+
+    let audio_format = match sample_format {
+        SampleFormat::PcmInt => WAVE_FORMAT_PCM,
+        SampleFormat::IeeeFloat => WAVE_FORMAT_IEEE_FLOAT,
+    };
+
+    write_all(out, riff_magic(endianness))?;
+    write_u32(out, chunk_size, endianness)?;
+    write_all(out, b"WAVE")?;
+
+    write_all(out, b"fmt ")?;
+    write_u32(out, 16, endianness)?;
+    write_u16(out, audio_format, endianness)?;
+    write_u16(out, num_channels, endianness)?;
+    write_u32(out, sample_rate, endianness)?;
+    write_u32(out, byte_rate, endianness)?;
+    write_u16(out, block_align as u16, endianness)?;
+    write_u16(out, bits_per_sample, endianness)?;
+
+    write_all(out, b"data")?;
+    write_u32(out, data_size, endianness)?;
+
+    let mut buf = vec![0u8; bytes_per_sample as usize];
+    for frame in frames {
+        for &sample in frame {
+            encode_sample(sample, bits_per_sample, sample_format, endianness, &mut buf);
+            write_all(out, &buf)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn riff_magic(endianness: Endianness) -> &'static [u8; 4] {
+    match endianness {
+        Endianness::Little => b"RIFF",
+        Endianness::Big => b"RIFX",
+    }
+}
+
+fn write_all(out: &mut dyn io::Write, buf: &[u8]) -> Result<(), String> {
+    out.write_all(buf).map_err(|e| e.to_string())
+}
+
+fn write_u32(out: &mut dyn io::Write, v: u32, endianness: Endianness) -> Result<(), String> {
+    let bytes = match endianness {
+        Endianness::Little => v.to_le_bytes(),
+        Endianness::Big => v.to_be_bytes(),
+    };
+    write_all(out, &bytes)
+}
+
+fn write_u16(out: &mut dyn io::Write, v: u16, endianness: Endianness) -> Result<(), String> {
+    let bytes = match endianness {
+        Endianness::Little => v.to_le_bytes(),
+        Endianness::Big => v.to_be_bytes(),
+    };
+    write_all(out, &bytes)
+}
+
+fn encode_sample(
+    sample: Sample,
+    bits_per_sample: u16,
+    sample_format: SampleFormat,
+    endianness: Endianness,
+    buf: &mut [u8],
+) {
+    match (sample_format, bits_per_sample) {
+        (SampleFormat::PcmInt, 8) => {
+            let i = as_int(sample).clamp(-128, 127);
+            buf[0] = (i + 128) as u8;
+        }
+        (SampleFormat::PcmInt, 16) => {
+            let i = as_int(sample).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+            let bytes = match endianness {
+                Endianness::Little => i.to_le_bytes(),
+                Endianness::Big => i.to_be_bytes(),
+            };
+            buf.copy_from_slice(&bytes);
+        }
+        (SampleFormat::PcmInt, 24) => {
+            let i = as_int(sample).clamp(-(1 << 23), (1 << 23) - 1);
+            match endianness {
+                Endianness::Little => buf.copy_from_slice(&i.to_le_bytes()[0..3]),
+                Endianness::Big => buf.copy_from_slice(&i.to_be_bytes()[1..4]),
+            }
+        }
+        (SampleFormat::PcmInt, 32) => {
+            let bytes = match endianness {
+                Endianness::Little => as_int(sample).to_le_bytes(),
+                Endianness::Big => as_int(sample).to_be_bytes(),
+            };
+            buf.copy_from_slice(&bytes);
+        }
+        (SampleFormat::IeeeFloat, 32) => {
+            let bytes = match endianness {
+                Endianness::Little => as_float(sample).to_le_bytes(),
+                Endianness::Big => as_float(sample).to_be_bytes(),
+            };
+            buf.copy_from_slice(&bytes);
+        }
+        (format, width) => unreachable!("unsupported sample encoding: {format:?} at {width} bits"),
+    }
+}
+
+fn as_int(sample: Sample) -> i32 {
+    match sample {
+        Sample::Int(i) => i,
+        Sample::Float(f) => (f * i32::MAX as f32) as i32,
+    }
+}
+
+fn as_float(sample: Sample) -> f32 {
+    match sample {
+        Sample::Int(i) => i as f32 / i32::MAX as f32,
+        Sample::Float(f) => f,
+    }
+}
+
+#[test]
+fn test_write_wave_roundtrips_16bit_header_and_samples() {
+    let frames = vec![
+        vec![Sample::Int(0), Sample::Int(-1)],
+        vec![Sample::Int(100), Sample::Int(-100)],
+    ];
+
+    let mut out = Vec::new();
+    write_wave(
+        &mut out,
+        2,
+        44100,
+        16,
+        SampleFormat::PcmInt,
+        Endianness::Little,
+        &frames,
+    )
+    .unwrap();
+
+    assert_eq!(&out[0..4], b"RIFF");
+    assert_eq!(&out[8..12], b"WAVE");
+    assert_eq!(&out[12..16], b"fmt ");
+    assert_eq!(&out[36..40], b"data");
+
+    let data_size = u32::from_le_bytes(out[40..44].try_into().unwrap());
+    assert_eq!(data_size, 2 * 2 * 2); // 2 frames * 2 channels * 2 bytes
+
+    let mut cursor = io::Cursor::new(out.clone());
+    let header = crate::wav::parse_wave_header(&mut cursor).unwrap();
+    assert_eq!(header.data_len, data_size);
+
+    let decoded: Result<Vec<Frame>, String> =
+        crate::decode::FrameDecoder::new(&header, &mut cursor).collect();
+    assert_eq!(decoded.unwrap(), frames);
+}
+
+#[test]
+fn test_write_wave_rifx_big_endian() {
+    let frames = vec![vec![Sample::Int(256)]];
+
+    let mut out = Vec::new();
+    write_wave(
+        &mut out,
+        1,
+        44100,
+        16,
+        SampleFormat::PcmInt,
+        Endianness::Big,
+        &frames,
+    )
+    .unwrap();
+
+    assert_eq!(&out[0..4], b"RIFX");
+
+    let header = crate::wav::parse_wave_header(&mut io::Cursor::new(out.clone())).unwrap();
+    assert_eq!(header.endianness(), Endianness::Big);
+    assert_eq!(header.num_channels(), 1);
+}
+
+#[test]
+fn test_encode_8bit_applies_bias() {
+    let mut buf = [0u8; 1];
+    encode_sample(Sample::Int(0), 8, SampleFormat::PcmInt, Endianness::Little, &mut buf);
+    assert_eq!(buf[0], 128);
+
+    encode_sample(Sample::Int(-128), 8, SampleFormat::PcmInt, Endianness::Little, &mut buf);
+    assert_eq!(buf[0], 0);
+}